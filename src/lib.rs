@@ -0,0 +1,181 @@
+use colored::{Color, ColoredString, Colorize};
+use std::str::FromStr;
+
+/// Environment variable used to override the default output colors,
+/// e.g. `DOTENV_LINTER_COLORS="warning=yellow:check=cyan:path=bold"`.
+pub const COLORS_ENV: &str = "DOTENV_LINTER_COLORS";
+
+/// A single color/attribute combination applied to one semantic role.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    color: Option<Color>,
+    bold: bool,
+}
+
+impl Style {
+    const fn new(color: Option<Color>, bold: bool) -> Self {
+        Style { color, bold }
+    }
+
+    /// Renders `text` with this style.
+    pub fn paint(self, text: &str) -> ColoredString {
+        let mut styled = match self.color {
+            Some(color) => text.color(color),
+            None => text.normal(),
+        };
+        if self.bold {
+            styled = styled.bold();
+        }
+        styled
+    }
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut color = None;
+        let mut bold = false;
+        for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            if token.eq_ignore_ascii_case("bold") {
+                bold = true;
+            } else {
+                color = Some(token.parse().map_err(|_| format!("unknown color: {}", token))?);
+            }
+        }
+        Ok(Style::new(color, bold))
+    }
+}
+
+/// Colors used for each semantic role in the output, resolved once at
+/// startup from [`COLORS_ENV`] with the hardcoded defaults as a fallback.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// The warning message itself.
+    pub warning: Style,
+    /// The name of the check that produced a warning.
+    pub check: Style,
+    /// File paths.
+    pub path: Style,
+    /// Lines rewritten by `fix`.
+    pub fixed: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            warning: Style::new(Some(Color::Yellow), false),
+            check: Style::new(Some(Color::Red), false),
+            path: Style::new(None, true),
+            fixed: Style::new(Some(Color::Green), false),
+        }
+    }
+}
+
+impl Theme {
+    /// Parses a `key=style:key=style` spec, overriding the defaults for any
+    /// role named and leaving the rest untouched. Unrecognized roles and
+    /// unparseable styles (including unknown color names) are ignored, so
+    /// the affected role keeps its default.
+    pub fn from_spec(spec: &str) -> Self {
+        let mut theme = Theme::default();
+        for pair in spec.split(':').filter(|p| !p.is_empty()) {
+            let (role, value) = match pair.split_once('=') {
+                Some(parts) => parts,
+                None => continue,
+            };
+            let style = match value.parse() {
+                Ok(style) => style,
+                Err(_) => continue,
+            };
+            match role.trim() {
+                "warning" => theme.warning = style,
+                "check" => theme.check = style,
+                "path" => theme.path = style,
+                "fixed" => theme.fixed = style,
+                _ => {}
+            }
+        }
+        theme
+    }
+
+    /// Resolves the theme from the environment.
+    pub fn from_env() -> Self {
+        match std::env::var(COLORS_ENV) {
+            Ok(spec) => Theme::from_spec(&spec),
+            Err(_) => Theme::default(),
+        }
+    }
+}
+
+/// Output format for warnings, modelled on cargo's `MessageFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Colored, human-readable text (the default).
+    Human,
+    /// A single JSON array of `{file, line, check_name, message}` objects.
+    Json,
+    /// A `<checkstyle>` document consumable by IDEs and CI annotators.
+    Checkstyle,
+}
+
+impl Format {
+    /// Whether this format should be rendered with ANSI colors.
+    pub fn is_colored(self) -> bool {
+        matches!(self, Format::Human)
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            "checkstyle" => Ok(Format::Checkstyle),
+            other => Err(format!("unknown format: {}", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn style_parses_color_and_bold_in_any_order() {
+        let colored_first: Style = "green,bold".parse().unwrap();
+        assert_eq!(colored_first.color, Some(Color::Green));
+        assert!(colored_first.bold);
+
+        let bold_first: Style = "bold,green".parse().unwrap();
+        assert_eq!(bold_first.color, Some(Color::Green));
+        assert!(bold_first.bold);
+    }
+
+    #[test]
+    fn style_rejects_unknown_color() {
+        assert!("notacolor".parse::<Style>().is_err());
+    }
+
+    #[test]
+    fn from_spec_keeps_defaults_for_unknown_color_and_role() {
+        let defaults = Theme::default();
+        let theme = Theme::from_spec("warning=notacolor:bogus=red:check=cyan");
+
+        // An unknown color leaves the role at its default.
+        assert_eq!(theme.warning.color, defaults.warning.color);
+        // An unknown role is ignored and the other defaults are untouched.
+        assert_eq!(theme.path.bold, defaults.path.bold);
+        assert_eq!(theme.fixed.color, defaults.fixed.color);
+        // A valid override is applied.
+        assert_eq!(theme.check.color, Some(Color::Cyan));
+    }
+
+    #[test]
+    fn format_parses_known_values() {
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
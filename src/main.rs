@@ -1,8 +1,45 @@
 use clap::{AppSettings, Arg, SubCommand};
+use dotenv_linter::{Format, Theme};
 use std::error::Error;
 use std::ffi::OsStr;
+use std::str::FromStr;
 use std::{env, process};
 
+/// When to colorize output, modelled on clap's own colorizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorWhen {
+    /// Colorize only when stdout is connected to a terminal (the default).
+    Auto,
+    /// Always colorize, even when piped.
+    Always,
+    /// Never colorize.
+    Never,
+}
+
+impl ColorWhen {
+    /// Whether ANSI colors should be emitted given the current stdout.
+    fn is_colored(self) -> bool {
+        match self {
+            ColorWhen::Auto => atty::is(atty::Stream::Stdout),
+            ColorWhen::Always => true,
+            ColorWhen::Never => false,
+        }
+    }
+}
+
+impl FromStr for ColorWhen {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorWhen::Auto),
+            "always" => Ok(ColorWhen::Always),
+            "never" => Ok(ColorWhen::Never),
+            other => Err(format!("unknown color option: {}", other)),
+        }
+    }
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     #[cfg(windows)]
     colored::control::set_virtual_terminal(true).ok();
@@ -12,16 +49,18 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     disable_color_output(&args);
 
+    let theme = Theme::from_env();
+
     match args.subcommand() {
         ("", None) => {
-            let total_warnings = dotenv_linter::check(&args, &current_dir)?;
-
-            if total_warnings == 0 {
-                process::exit(0);
-            }
+            run_check(&args, &current_dir, &theme)?;
         }
         ("fix", Some(fix_args)) => {
-            dotenv_linter::fix(&fix_args, &current_dir)?;
+            disable_color_output(&fix_args);
+
+            let format = format_from_args(fix_args);
+            let verbosity = verbosity_from_args(&args, Some(fix_args));
+            dotenv_linter::fix(&fix_args, &current_dir, format, verbosity, &theme)?;
             process::exit(0);
         }
         ("list", Some(_)) => {
@@ -34,28 +73,158 @@ fn main() -> Result<(), Box<dyn Error>> {
         ("compare", Some(compare_args)) => {
             disable_color_output(&compare_args);
 
-            let warnings = dotenv_linter::compare(&compare_args, &current_dir)?;
+            let format = format_from_args(compare_args);
+            let verbosity = verbosity_from_args(&args, Some(compare_args));
+            let warnings =
+                dotenv_linter::compare(&compare_args, &current_dir, format, verbosity, &theme)?;
             if warnings.is_empty() {
                 process::exit(0);
             }
         }
-        _ => {
-            eprintln!("unknown command");
+        (external, external_matches) => {
+            // A readable path in subcommand position is the core lint target,
+            // not a plugin (e.g. `dotenv-linter .env`). Re-parse without
+            // external-subcommand handling so it binds to `input`.
+            if current_dir.join(external).exists() {
+                let args = get_args_without_externals(current_dir.as_os_str());
+                disable_color_output(&args);
+                run_check(&args, &current_dir, &theme)?;
+            } else {
+                let external_args = external_matches
+                    .and_then(|matches| matches.values_of(""))
+                    .map(|values| values.collect::<Vec<_>>())
+                    .unwrap_or_default();
+
+                if !execute_external_subcommand(external, &external_args, &current_dir)? {
+                    eprintln!("unknown command");
+                }
+            }
         }
     }
 
     process::exit(1);
 }
 
+/// Runs the default check over the `input` paths and exits 0 when clean.
+fn run_check(
+    args: &clap::ArgMatches,
+    current_dir: &std::path::Path,
+    theme: &Theme,
+) -> Result<(), Box<dyn Error>> {
+    let format = format_from_args(args);
+    let verbosity = verbosity_from_args(args, None);
+    let total_warnings = dotenv_linter::check(args, current_dir, format, verbosity, theme)?;
+
+    if total_warnings == 0 {
+        process::exit(0);
+    }
+
+    Ok(())
+}
+
+/// Runs a `dotenv-linter-<name>` plugin found on `PATH`, forwarding the
+/// remaining args and the current directory, and exits with its status.
+/// Returns `Ok(false)` (without exiting) when no such binary exists, so the
+/// caller can report the usual "unknown command" error.
+fn execute_external_subcommand(
+    name: &str,
+    args: &[&str],
+    current_dir: &std::path::Path,
+) -> Result<bool, Box<dyn Error>> {
+    let binary = format!("{}-{}", env!("CARGO_PKG_NAME"), name);
+
+    if which(&binary).is_none() {
+        return Ok(false);
+    }
+
+    let status = process::Command::new(&binary)
+        .args(args)
+        .env("DOTENV_LINTER_CURRENT_DIR", current_dir)
+        .status()?;
+
+    process::exit(status.code().unwrap_or(1));
+}
+
+/// Looks up an executable by name on `PATH`.
+fn which(binary: &str) -> Option<std::path::PathBuf> {
+    let paths = env::var_os("PATH")?;
+    env::split_paths(&paths).find_map(|dir| {
+        let candidate = dir.join(binary);
+        #[cfg(unix)]
+        let executable = {
+            use std::os::unix::fs::PermissionsExt;
+            candidate
+                .metadata()
+                .map_or(false, |meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+        };
+        #[cfg(not(unix))]
+        let executable = candidate.is_file();
+        if executable {
+            Some(candidate)
+        } else {
+            None
+        }
+    })
+}
+
+fn format_from_args(args: &clap::ArgMatches) -> Format {
+    args.value_of("format")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(Format::Human)
+}
+
+/// Resolves the requested verbosity level: a negative value means quiet,
+/// zero is the default, and each `-v` raises it by one.
+///
+/// `quiet`/`verbose` are `global(true)`, but clap 2 stores the value in the
+/// matches of whichever level actually received the flag, so we look in both
+/// the root and the subcommand matches — this makes `fix -q` and `compare -v`
+/// (flags given *after* the subcommand) resolve the same as `-q fix`.
+fn verbosity_from_args(root: &clap::ArgMatches, sub: Option<&clap::ArgMatches>) -> i8 {
+    let quiet =
+        root.is_present("quiet") || sub.map_or(false, |args| args.is_present("quiet"));
+    if quiet {
+        return -1;
+    }
+
+    let verbose = root
+        .occurrences_of("verbose")
+        .max(sub.map_or(0, |args| args.occurrences_of("verbose")));
+    verbose as i8
+}
+
 fn get_args(current_dir: &OsStr) -> clap::ArgMatches {
-    clap::App::new(env!("CARGO_PKG_NAME"))
+    app(current_dir, true).get_matches()
+}
+
+/// Re-parses the command line with external subcommands disabled, so a bare
+/// path (which clap would otherwise treat as an external subcommand name)
+/// binds to the `input` positional and gets linted.
+fn get_args_without_externals(current_dir: &OsStr) -> clap::ArgMatches {
+    app(current_dir, false).get_matches()
+}
+
+/// Builds the CLI. `allow_external` enables clap's external-subcommand
+/// handling; it is turned off when re-parsing a bare path (see `main`) so the
+/// path binds to the `input` positional instead of being mistaken for a plugin.
+fn app(current_dir: &OsStr, allow_external: bool) -> clap::App {
+    let mut app = clap::App::new(env!("CARGO_PKG_NAME"))
         .setting(AppSettings::ColoredHelp)
         .setting(AppSettings::DisableHelpSubcommand)
-        .setting(AppSettings::VersionlessSubcommands)
-        .about(env!("CARGO_PKG_DESCRIPTION"))
+        .setting(AppSettings::VersionlessSubcommands);
+
+    if allow_external {
+        app = app.setting(AppSettings::AllowExternalSubcommands);
+    }
+
+    app.about(env!("CARGO_PKG_DESCRIPTION"))
         .author(env!("CARGO_PKG_AUTHORS"))
         .version(env!("CARGO_PKG_VERSION"))
-        .version_short("v")
+        // `-v` now means `--verbose`; the version flag moved to `-V` to make
+        // room for it (matching cargo and most Rust CLIs).
+        .version_short("V")
+        .arg(quiet_flag().global(true))
+        .arg(verbose_flag().global(true))
         .args(common_args(current_dir).as_ref())
         .subcommand(
             SubCommand::with_name("list")
@@ -87,21 +256,48 @@ fn get_args(current_dir: &OsStr) -> clap::ArgMatches {
                         .multiple(true)
                         .min_values(2)
                         .required(true),
+                    color_flag(),
                     no_color_flag(),
-                    quiet_flag(),
+                    format_flag(),
                 ])
                 .about("Compares if files have the same keys")
                 .usage("dotenv-linter compare <files>..."),
         )
-        .get_matches()
 }
 
 fn disable_color_output(args: &clap::ArgMatches) {
-    if args.is_present("no-color") {
+    if !format_from_args(args).is_colored() {
         colored::control::set_override(false);
+        return;
+    }
+
+    let color = if args.is_present("no-color") {
+        ColorWhen::Never
+    } else {
+        args.value_of("color")
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(ColorWhen::Auto)
+    };
+
+    match color {
+        // `Auto` still forces an override, but based on whether stdout is a
+        // terminal so colors are stripped when piped and kept when not.
+        ColorWhen::Auto => colored::control::set_override(color.is_colored()),
+        ColorWhen::Always => colored::control::set_override(true),
+        ColorWhen::Never => colored::control::set_override(false),
     }
 }
 
+fn format_flag() -> clap::Arg<'static, 'static> {
+    Arg::with_name("format")
+        .long("format")
+        .value_name("FORMAT")
+        .help("The output format of the warnings")
+        .takes_value(true)
+        .possible_values(&["human", "json", "checkstyle"])
+        .default_value("human")
+}
+
 fn quiet_flag() -> clap::Arg<'static, 'static> {
     Arg::with_name("quiet")
         .short("q")
@@ -109,10 +305,29 @@ fn quiet_flag() -> clap::Arg<'static, 'static> {
         .help("Doesn't display additional information")
 }
 
+fn verbose_flag() -> clap::Arg<'static, 'static> {
+    Arg::with_name("verbose")
+        .short("v")
+        .long("verbose")
+        .multiple(true)
+        .help("Uses verbose output (can be repeated); use -V for the version")
+}
+
+fn color_flag() -> clap::Arg<'static, 'static> {
+    Arg::with_name("color")
+        .long("color")
+        .value_name("WHEN")
+        .help("Controls when to use colored output")
+        .takes_value(true)
+        .possible_values(&["auto", "always", "never"])
+        .default_value("auto")
+}
+
 fn no_color_flag() -> clap::Arg<'static, 'static> {
     Arg::with_name("no-color")
         .long("no-color")
-        .help("Turns off the colored output")
+        .hidden(true)
+        .help("Turns off the colored output (alias for --color never)")
 }
 
 fn common_args(current_dir: &OsStr) -> Vec<Arg> {
@@ -141,7 +356,44 @@ fn common_args(current_dir: &OsStr) -> Vec<Arg> {
             .short("r")
             .long("recursive")
             .help("Recursively searches and checks .env files"),
+        color_flag(),
         no_color_flag(),
-        quiet_flag(),
+        format_flag(),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(argv: &[&str]) -> clap::ArgMatches<'static> {
+        app(OsStr::new("."), true).get_matches_from(argv)
+    }
+
+    #[test]
+    fn quiet_resolves_when_given_after_subcommand() {
+        let args = matches(&["dotenv-linter", "fix", "-q", "."]);
+        let (_, sub) = args.subcommand();
+        assert_eq!(verbosity_from_args(&args, sub), -1);
+    }
+
+    #[test]
+    fn verbose_resolves_when_given_after_subcommand() {
+        let args = matches(&["dotenv-linter", "compare", "-v", ".env", ".env.dist"]);
+        let (_, sub) = args.subcommand();
+        assert_eq!(verbosity_from_args(&args, sub), 1);
+    }
+
+    #[test]
+    fn quiet_resolves_when_given_before_subcommand() {
+        let args = matches(&["dotenv-linter", "-q", "fix", "."]);
+        let (_, sub) = args.subcommand();
+        assert_eq!(verbosity_from_args(&args, sub), -1);
+    }
+
+    #[test]
+    fn color_when_parses_known_values() {
+        assert_eq!("always".parse::<ColorWhen>().unwrap(), ColorWhen::Always);
+        assert!("sometimes".parse::<ColorWhen>().is_err());
+    }
+}